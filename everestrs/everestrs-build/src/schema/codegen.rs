@@ -0,0 +1,357 @@
+//! Rust code generation from a parsed [`Interface`].
+//!
+//! Borrowing the approach of QAPI-style schema-to-code tooling, this module
+//! turns an interface into strongly-typed serde structs and enums: one struct
+//! per command's `arguments`, a struct or alias per command `result`, and a
+//! type per `var`. The output is a [`proc_macro2::TokenStream`] (or its string
+//! rendering) suitable for emission from a build script, giving module authors
+//! compile-checked bindings instead of hand-written serde definitions.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::interface::{Argument, Interface, StringOptions, Type, Variable};
+
+/// Generate Rust bindings for `interface`, returned as the token stream's
+/// string rendering (not pretty-printed), ready to be written to `OUT_DIR`
+/// from a build script.
+pub fn generate(interface: &Interface) -> String {
+    generate_tokens(interface).to_string()
+}
+
+/// Generate Rust bindings for `interface` as a token stream.
+pub fn generate_tokens(interface: &Interface) -> TokenStream {
+    let mut generator = Generator::default();
+    for (name, command) in &interface.cmds {
+        let base = to_pascal_case(name);
+        // Every declared argument is a required field.
+        let required: std::collections::HashSet<String> =
+            command.arguments.keys().cloned().collect();
+        generator.emit_struct(
+            &format!("{base}Arguments"),
+            &PropertiesRequired {
+                properties: &command.arguments,
+                required: &required,
+            },
+        );
+        if let Some(result) = &command.result {
+            generator.emit_named(&format!("{base}Result"), result);
+        }
+    }
+    for (name, variable) in &interface.vars {
+        generator.emit_named(&to_pascal_case(name), variable);
+    }
+    let items = generator.items;
+    quote! { #(#items)* }
+}
+
+#[derive(Default)]
+struct Generator {
+    items: Vec<TokenStream>,
+}
+
+impl Generator {
+    /// Emit a named top-level binding for `variable`. Aggregate kinds (object,
+    /// union, string enum) become their own type; scalars and arrays become a
+    /// type alias.
+    fn emit_named(&mut self, name: &str, variable: &Variable) {
+        match &variable.arg {
+            // Object, string `enum`, and union variables map to a type that
+            // `rust_type` already emits *under `name` itself*. Binding them
+            // again with `pub type name = name;` would both redefine the item
+            // (E0428) and alias it to itself (E0391), so emit the definition
+            // directly and skip the alias.
+            Argument::Single(Type::Object(opts)) if opts.object_reference.is_none() => {
+                self.emit_struct(name, &opts.properties_required());
+            }
+            Argument::Single(Type::String(opts)) if opts.enum_items.is_some() => {
+                self.string_type(opts, name);
+            }
+            Argument::Multiple(_) => {
+                self.rust_type(&variable.arg, name);
+            }
+            // Scalars and arrays have no named counterpart, so a transparent
+            // alias is the right binding.
+            _ => {
+                let ty = self.rust_type(&variable.arg, name);
+                let ident = format_ident!("{}", name);
+                self.items.push(quote! { pub type #ident = #ty; });
+            }
+        }
+    }
+
+    /// Emit a `struct name { .. }` from a map of properties, honouring the
+    /// required set for `Option<T>` vs `T`.
+    fn emit_struct(&mut self, name: &str, props: &PropertiesRequired) {
+        let ident = format_ident!("{}", name);
+        let mut fields = Vec::new();
+        let mut used = std::collections::HashSet::new();
+        for (prop_name, variable) in &props.properties {
+            let suggested = format!("{name}{}", to_pascal_case(prop_name));
+            let mut ty = self.rust_type(&variable.arg, &suggested);
+            if !props.required.contains(prop_name.as_str()) {
+                ty = quote! { Option<#ty> };
+            }
+            // Uniquify the normalized name so two properties that collapse to
+            // the same snake_case (e.g. `fooBar` and `foo_bar`) do not emit
+            // duplicate fields (E0428).
+            let unique = unique_name(to_snake_case(prop_name), &mut used);
+            let field_ident = make_ident(&unique);
+            // A `#[serde(rename)]` is required whenever the identifier no longer
+            // matches the wire name — because it was normalized, disambiguated,
+            // prefixed with `_`, or escaped as a keyword (`r#type`, `self_`).
+            let rename_attr = if field_ident.to_string() != *prop_name {
+                Some(quote! { #[serde(rename = #prop_name)] })
+            } else {
+                None
+            };
+            let doc = variable
+                .description
+                .as_ref()
+                .map(|d| quote! { #[doc = #d] });
+            fields.push(quote! {
+                #doc
+                #rename_attr
+                pub #field_ident: #ty,
+            });
+        }
+        self.items.push(quote! {
+            #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize)]
+            pub struct #ident {
+                #(#fields)*
+            }
+        });
+    }
+
+    /// Map an [`Argument`] to a Rust type, registering any helper types the
+    /// mapping requires and returning the token stream that names the type.
+    fn rust_type(&mut self, arg: &Argument, suggested_name: &str) -> TokenStream {
+        match arg {
+            Argument::Single(t) => self.rust_type_for(t, suggested_name),
+            Argument::Multiple(types) => {
+                let ident = format_ident!("{}", suggested_name);
+                let variants = types.iter().enumerate().map(|(i, t)| {
+                    let variant = format_ident!("Variant{i}");
+                    let ty = self.rust_type_for(t, &format!("{suggested_name}Variant{i}"));
+                    quote! { #variant(#ty), }
+                });
+                let variants: Vec<_> = variants.collect();
+                self.items.push(quote! {
+                    #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize)]
+                    #[serde(untagged)]
+                    pub enum #ident {
+                        #(#variants)*
+                    }
+                });
+                quote! { #ident }
+            }
+        }
+    }
+
+    fn rust_type_for(&mut self, t: &Type, suggested_name: &str) -> TokenStream {
+        match t {
+            Type::Null => quote! { () },
+            Type::Boolean => quote! { bool },
+            Type::Integer(_) => quote! { i64 },
+            Type::Number(_) => quote! { f64 },
+            Type::String(opts) => self.string_type(opts, suggested_name),
+            Type::Array(opts) => {
+                let item = match &opts.items {
+                    Some(item) => self.rust_type(&item.arg, &format!("{suggested_name}Item")),
+                    None => quote! { ::serde_yaml::Value },
+                };
+                quote! { Vec<#item> }
+            }
+            Type::Object(opts) => {
+                if opts.object_reference.is_some() {
+                    // An unresolved `$ref` has no local binding; fall back to a
+                    // dynamic value. Run `resolve_all` first for a typed result.
+                    return quote! { ::serde_yaml::Value };
+                }
+                let ident = format_ident!("{}", suggested_name);
+                self.emit_struct(suggested_name, &opts.properties_required());
+                quote! { #ident }
+            }
+        }
+    }
+
+    /// A string with an `enum` becomes a Rust enum; otherwise a `String`.
+    fn string_type(&mut self, opts: &StringOptions, suggested_name: &str) -> TokenStream {
+        let Some(items) = &opts.enum_items else {
+            return quote! { String };
+        };
+        let ident = format_ident!("{}", suggested_name);
+        let mut used = std::collections::HashSet::new();
+        let variants: Vec<_> = items
+            .iter()
+            .map(|item| {
+                // Uniquify so two values that collapse to the same PascalCase
+                // do not emit duplicate variants (E0428).
+                let unique = unique_name(to_pascal_case(item), &mut used);
+                let variant = make_ident(&unique);
+                let rename_attr = if variant.to_string() != *item {
+                    Some(quote! { #[serde(rename = #item)] })
+                } else {
+                    None
+                };
+                quote! { #rename_attr #variant, }
+            })
+            .collect();
+        self.items.push(quote! {
+            #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize)]
+            pub enum #ident {
+                #(#variants)*
+            }
+        });
+        quote! { #ident }
+    }
+}
+
+/// The subset of [`ObjectOptions`](super::interface::ObjectOptions) the struct
+/// emitter needs, borrowed from the parsed model.
+struct PropertiesRequired<'a> {
+    properties: &'a std::collections::BTreeMap<String, Variable>,
+    required: &'a std::collections::HashSet<String>,
+}
+
+impl super::interface::ObjectOptions {
+    fn properties_required(&self) -> PropertiesRequired<'_> {
+        PropertiesRequired {
+            properties: &self.properties,
+            required: &self.required,
+        }
+    }
+}
+
+/// Build a legal identifier from `candidate`, which may be empty, start with a
+/// digit, or be a Rust keyword. Empty/leading-digit candidates are prefixed
+/// with `_`; keywords become raw identifiers (`r#type`) where permitted, and
+/// the handful of keywords that cannot be raw (`self`, `Self`, `super`,
+/// `crate`) are suffixed with `_`.
+fn make_ident(candidate: &str) -> proc_macro2::Ident {
+    let base = if candidate.is_empty() || candidate.chars().next().unwrap().is_ascii_digit() {
+        format!("_{candidate}")
+    } else {
+        candidate.to_string()
+    };
+    if NON_RAW_KEYWORDS.contains(&base.as_str()) {
+        format_ident!("{}_", base)
+    } else if is_keyword(&base) {
+        proc_macro2::Ident::new_raw(&base, proc_macro2::Span::call_site())
+    } else {
+        format_ident!("{}", base)
+    }
+}
+
+/// Return `base` if unused, otherwise suffix it (`base_2`, `base_3`, ...) until
+/// it is unique, recording the chosen name in `used`.
+fn unique_name(base: String, used: &mut std::collections::HashSet<String>) -> String {
+    if used.insert(base.clone()) {
+        return base;
+    }
+    let mut n = 2;
+    loop {
+        let candidate = format!("{base}_{n}");
+        if used.insert(candidate.clone()) {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+/// Keywords that `proc_macro2` refuses to turn into raw identifiers and so must
+/// be escaped by other means.
+const NON_RAW_KEYWORDS: &[&str] = &["self", "Self", "super", "crate"];
+
+/// Whether `word` is a Rust keyword (strict, reserved, or a weak keyword that
+/// is nonetheless rejected by `Ident::new`).
+fn is_keyword(word: &str) -> bool {
+    matches!(
+        word,
+        "as" | "break"
+            | "const"
+            | "continue"
+            | "crate"
+            | "dyn"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "self"
+            | "Self"
+            | "static"
+            | "struct"
+            | "super"
+            | "trait"
+            | "true"
+            | "type"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+            | "async"
+            | "await"
+            | "abstract"
+            | "become"
+            | "box"
+            | "do"
+            | "final"
+            | "macro"
+            | "override"
+            | "priv"
+            | "typeof"
+            | "unsized"
+            | "virtual"
+            | "yield"
+            | "try"
+    )
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_ascii_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.push(ch.to_ascii_lowercase());
+        } else if ch.is_ascii_alphanumeric() {
+            out.push(ch);
+        } else {
+            out.push('_');
+        }
+    }
+    out
+}
+
+fn to_pascal_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut capitalize = true;
+    for ch in name.chars() {
+        if ch.is_ascii_alphanumeric() {
+            if capitalize {
+                out.extend(ch.to_ascii_uppercase().to_string().chars());
+                capitalize = false;
+            } else {
+                out.push(ch);
+            }
+        } else {
+            capitalize = true;
+        }
+    }
+    out
+}
@@ -0,0 +1,146 @@
+//! Error-definition subsystem.
+//!
+//! An interface's `errors` list holds [`ErrorEntry`] hulls that only carry a
+//! `reference` of the form `/error_file#/error_name`. This module gives those
+//! references meaning: it parses error-definition files — each declaring named
+//! errors with a `description`, a `severity`, and an optional typed data
+//! payload — and resolves the references the same way type `$ref`s are
+//! resolved by the [`TypeRegistry`](super::registry::TypeRegistry).
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::interface::{ErrorEntry, Interface, Variable};
+
+/// How serious a raised error is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+}
+
+/// A named error declared in an error-definition file.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ErrorDefinition {
+    pub name: String,
+    pub description: String,
+    pub severity: Severity,
+    /// Optional typed payload carried alongside the error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Variable>,
+}
+
+/// An error-definition document: a list of named errors.
+#[derive(Debug, Deserialize)]
+struct ErrorDocument {
+    #[serde(default)]
+    errors: Vec<ErrorDefinition>,
+}
+
+/// Errors produced while resolving error references.
+#[derive(Debug)]
+pub enum ErrorResolveError {
+    MalformedReference(String),
+    Load { path: PathBuf, source: String },
+    UnknownError { path: PathBuf, name: String },
+}
+
+impl std::fmt::Display for ErrorResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorResolveError::MalformedReference(r) => {
+                write!(f, "malformed error reference {r:?}, expected 'file#/name'")
+            }
+            ErrorResolveError::Load { path, source } => {
+                write!(f, "failed to load error file {}: {source}", path.display())
+            }
+            ErrorResolveError::UnknownError { path, name } => {
+                write!(f, "no error {name:?} in {}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ErrorResolveError {}
+
+type Result<T> = std::result::Result<T, ErrorResolveError>;
+
+/// Loads and memoizes error-definition documents and resolves
+/// [`ErrorEntry`] references against them.
+#[derive(Debug)]
+pub struct ErrorRegistry {
+    base_dir: PathBuf,
+    documents: BTreeMap<PathBuf, ErrorDocument>,
+}
+
+impl ErrorRegistry {
+    /// Create a registry whose relative file parts are resolved against
+    /// `base_dir`.
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            documents: BTreeMap::new(),
+        }
+    }
+
+    /// Resolve a single `ErrorEntry` to its concrete [`ErrorDefinition`].
+    pub fn resolve(&mut self, entry: &ErrorEntry) -> Result<ErrorDefinition> {
+        let (path, name) = self.parse_reference(&entry.reference)?;
+        self.load(&path)?;
+        let doc = self.documents.get(&path).expect("just loaded");
+        doc.errors
+            .iter()
+            .find(|e| e.name == name)
+            .cloned()
+            .ok_or(ErrorResolveError::UnknownError { path, name })
+    }
+
+    fn load(&mut self, path: &Path) -> Result<()> {
+        if self.documents.contains_key(path) {
+            return Ok(());
+        }
+        let contents = std::fs::read_to_string(path).map_err(|e| ErrorResolveError::Load {
+            path: path.to_path_buf(),
+            source: e.to_string(),
+        })?;
+        let doc: ErrorDocument =
+            serde_yaml::from_str(&contents).map_err(|e| ErrorResolveError::Load {
+                path: path.to_path_buf(),
+                source: e.to_string(),
+            })?;
+        self.documents.insert(path.to_path_buf(), doc);
+        Ok(())
+    }
+
+    fn parse_reference(&self, reference: &str) -> Result<(PathBuf, String)> {
+        let (file, fragment) = reference
+            .split_once('#')
+            .ok_or_else(|| ErrorResolveError::MalformedReference(reference.to_string()))?;
+        let name = fragment.trim_start_matches('/');
+        if file.is_empty() || name.is_empty() {
+            return Err(ErrorResolveError::MalformedReference(reference.to_string()));
+        }
+        let mut path = self.base_dir.join(file.trim_start_matches('/'));
+        if path.extension().is_none() {
+            path.set_extension("yaml");
+        }
+        Ok((path, name.to_string()))
+    }
+}
+
+impl Interface {
+    /// Resolve every [`ErrorEntry`] in this interface's `errors` list into a
+    /// fully-populated [`ErrorDefinition`], loading error files relative to
+    /// `base_dir`.
+    pub fn resolve_errors(&self, base_dir: impl Into<PathBuf>) -> Result<Vec<ErrorDefinition>> {
+        let mut registry = ErrorRegistry::new(base_dir);
+        self.errors
+            .iter()
+            .map(|entry| registry.resolve(entry))
+            .collect()
+    }
+}
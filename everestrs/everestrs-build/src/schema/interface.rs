@@ -5,15 +5,27 @@ use std::collections::{BTreeMap, HashSet};
 #[serde(deny_unknown_fields)]
 pub struct Interface {
     pub description: String,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "dedup::commands")]
     pub cmds: BTreeMap<String, Command>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "dedup::variables")]
     pub vars: BTreeMap<String, Variable>,
     // The errors interface is currently just a hull.
     #[serde(default)]
     pub errors: Vec<ErrorEntry>,
 }
 
+impl Interface {
+    /// Parse an interface in *lenient* mode, where a key defined more than once
+    /// silently keeps its last occurrence instead of erroring. Strict parsing
+    /// (the default `Deserialize` impl) rejects duplicate `cmds`, `vars`, and
+    /// `properties` keys; round-tripping through `serde_yaml::Value` first
+    /// collapses the duplicates before the strict visitors ever see them.
+    pub fn from_yaml_lenient(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        let value: serde_yaml::Value = serde_yaml::from_str(yaml)?;
+        serde_yaml::from_value(value)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Command {
@@ -23,20 +35,20 @@ pub struct Command {
     pub result: Option<Variable>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Variable {
     pub description: Option<String>,
     pub arg: Argument,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum Argument {
     Single(Type),
     Multiple(Vec<Type>),
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct NumberOptions {
     pub minimum: Option<f64>,
@@ -44,7 +56,7 @@ pub struct NumberOptions {
     pub default: Option<f64>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct IntegerOptions {
     pub minimum: Option<i64>,
@@ -52,7 +64,7 @@ pub struct IntegerOptions {
     pub default: Option<i64>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct ArrayOptions {
     pub min_items: Option<usize>,
@@ -60,10 +72,10 @@ pub struct ArrayOptions {
     pub items: Option<Box<Variable>>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct ObjectOptions {
-    #[serde(default)]
+    #[serde(default, deserialize_with = "dedup::properties")]
     pub properties: BTreeMap<String, Variable>,
 
     #[serde(default)]
@@ -76,13 +88,82 @@ pub struct ObjectOptions {
     pub object_reference: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// The `format` vocabulary for string values, following the OpenAPI /
+/// JSON-Schema format keywords. Formats this crate does not recognise
+/// deserialize into [`StringFormat::Other`] rather than failing, so interfaces
+/// using newer or vendor-specific formats still load.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StringFormat {
-    #[serde(rename = "date-time")]
     DateTime,
+    Date,
+    Time,
+    Duration,
+    Uuid,
+    Email,
+    Uri,
+    Ipv4,
+    Ipv6,
+    Hostname,
+    Other(String),
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl StringFormat {
+    /// The canonical wire name of this format, as it appears in YAML.
+    pub fn name(&self) -> &str {
+        match self {
+            StringFormat::DateTime => "date-time",
+            StringFormat::Date => "date",
+            StringFormat::Time => "time",
+            StringFormat::Duration => "duration",
+            StringFormat::Uuid => "uuid",
+            StringFormat::Email => "email",
+            StringFormat::Uri => "uri",
+            StringFormat::Ipv4 => "ipv4",
+            StringFormat::Ipv6 => "ipv6",
+            StringFormat::Hostname => "hostname",
+            StringFormat::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for StringFormat {
+    fn from(value: &str) -> Self {
+        match value {
+            "date-time" => StringFormat::DateTime,
+            "date" => StringFormat::Date,
+            "time" => StringFormat::Time,
+            "duration" => StringFormat::Duration,
+            "uuid" => StringFormat::Uuid,
+            "email" => StringFormat::Email,
+            "uri" => StringFormat::Uri,
+            "ipv4" => StringFormat::Ipv4,
+            "ipv6" => StringFormat::Ipv6,
+            "hostname" => StringFormat::Hostname,
+            other => StringFormat::Other(other.to_string()),
+        }
+    }
+}
+
+impl Serialize for StringFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for StringFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Ok(StringFormat::from(raw.as_str()))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct StringOptions {
     pub pattern: Option<String>,
@@ -99,7 +180,7 @@ pub struct StringOptions {
     pub object_reference: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase", tag = "type", deny_unknown_fields)]
 pub enum Type {
     Null,
@@ -116,9 +197,10 @@ impl<'de> Deserialize<'de> for Variable {
     where
         D: Deserializer<'de>,
     {
-        let serde_yaml::Value::Mapping(mut map) = Deserialize::deserialize(deserializer)? else {
-            return Err(serde::de::Error::custom("Variable must be a mapping"));
-        };
+        // Read the mapping ourselves rather than via the `serde_yaml::Value`
+        // impl so duplicate keys (e.g. two `properties` or a repeated field)
+        // are rejected before they are silently collapsed.
+        let mut map = deserializer.deserialize_map(dedup::MappingVisitor)?;
 
         let description: Option<String> = match map.remove("description") {
             None => None,
@@ -161,7 +243,194 @@ impl<'de> Deserialize<'de> for Variable {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ErrorEntry {
     pub reference: String,
 }
+
+/// Duplicate-key-rejecting deserialization helpers.
+///
+/// `BTreeMap` (and the hand-built `serde_yaml::Mapping` in the [`Variable`]
+/// deserializer) silently keep only the last value when a key repeats, masking
+/// authoring mistakes like a command or property defined twice. These visitors
+/// insert into the target map and error the moment an already-present key is
+/// seen, reporting the offending key — the technique used by `serde_with`'s
+/// `maps_duplicate_key_is_error`.
+mod dedup {
+    use super::BTreeMap;
+    use serde::de::{Deserialize, Deserializer, Error, MapAccess, Visitor};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    /// Visitor for a `BTreeMap<String, V>` that rejects duplicate keys,
+    /// describing the offending entry with `noun` (e.g. `"command"`).
+    struct UniqueMap<V> {
+        noun: &'static str,
+        _marker: PhantomData<V>,
+    }
+
+    impl<'de, V> Visitor<'de> for UniqueMap<V>
+    where
+        V: Deserialize<'de>,
+    {
+        type Value = BTreeMap<String, V>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a map of unique {} names", self.noun)
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut map = BTreeMap::new();
+            while let Some((key, value)) = access.next_entry::<String, V>()? {
+                if map.contains_key(&key) {
+                    return Err(A::Error::custom(format!(
+                        "duplicate {} {:?}",
+                        self.noun, key
+                    )));
+                }
+                map.insert(key, value);
+            }
+            Ok(map)
+        }
+    }
+
+    fn unique_map<'de, D, V>(
+        deserializer: D,
+        noun: &'static str,
+    ) -> Result<BTreeMap<String, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Deserialize<'de>,
+    {
+        deserializer.deserialize_map(UniqueMap {
+            noun,
+            _marker: PhantomData,
+        })
+    }
+
+    pub(super) fn commands<'de, D, V>(deserializer: D) -> Result<BTreeMap<String, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Deserialize<'de>,
+    {
+        unique_map(deserializer, "command")
+    }
+
+    pub(super) fn variables<'de, D, V>(deserializer: D) -> Result<BTreeMap<String, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Deserialize<'de>,
+    {
+        unique_map(deserializer, "variable")
+    }
+
+    pub(super) fn properties<'de, D, V>(deserializer: D) -> Result<BTreeMap<String, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Deserialize<'de>,
+    {
+        unique_map(deserializer, "property")
+    }
+
+    /// Visitor building a `serde_yaml::Mapping` that rejects duplicate keys,
+    /// used by the hand-written [`Variable`] deserializer.
+    ///
+    /// The `properties` entry is streamed through [`PropertiesValue`] so nested
+    /// duplicate property names are caught *before* `serde_yaml` collapses the
+    /// sub-map — the `Variable` path never round-trips properties through a
+    /// plain `Value`, which would silently drop the duplicate.
+    pub(super) struct MappingVisitor;
+
+    impl<'de> Visitor<'de> for MappingVisitor {
+        type Value = serde_yaml::Mapping;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a mapping with unique keys")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut map = serde_yaml::Mapping::new();
+            while let Some(key) = access.next_key::<serde_yaml::Value>()? {
+                if map.contains_key(&key) {
+                    let shown = key.as_str().map(str::to_string).unwrap_or_default();
+                    return Err(A::Error::custom(format!("duplicate key {shown:?}")));
+                }
+                let value = match key.as_str() {
+                    Some("properties") => access.next_value::<PropertiesValue>()?.0,
+                    // An array's `items` is itself a variable definition.
+                    Some("items") => access.next_value::<VariableMapping>()?.0,
+                    _ => access.next_value::<serde_yaml::Value>()?,
+                };
+                map.insert(key, value);
+            }
+            Ok(map)
+        }
+    }
+
+    /// A `properties` sub-map deserialized as a `serde_yaml::Value`, rejecting
+    /// duplicate property names while streaming. Nested properties are handled
+    /// recursively: each property value is streamed through [`MappingVisitor`],
+    /// which applies this same guard to its own `properties`.
+    pub(super) struct PropertiesValue(pub serde_yaml::Value);
+
+    impl<'de> Deserialize<'de> for PropertiesValue {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(PropertiesVisitor).map(Self)
+        }
+    }
+
+    struct PropertiesVisitor;
+
+    impl<'de> Visitor<'de> for PropertiesVisitor {
+        type Value = serde_yaml::Value;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a map of unique property names")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut map = serde_yaml::Mapping::new();
+            while let Some(key) = access.next_key::<serde_yaml::Value>()? {
+                if map.contains_key(&key) {
+                    let shown = key.as_str().map(str::to_string).unwrap_or_default();
+                    return Err(A::Error::custom(format!("duplicate property {shown:?}")));
+                }
+                // Each property value is itself a variable definition; stream it
+                // through `MappingVisitor` so its own nested `properties` are
+                // guarded at arbitrary depth rather than collapsed into a plain
+                // `Value`.
+                let value = access.next_value::<VariableMapping>()?.0;
+                map.insert(key, value);
+            }
+            Ok(serde_yaml::Value::Mapping(map))
+        }
+    }
+
+    /// A variable definition read as a `serde_yaml::Value` through
+    /// [`MappingVisitor`], preserving duplicate-property detection at every
+    /// level of nesting.
+    struct VariableMapping(serde_yaml::Value);
+
+    impl<'de> Deserialize<'de> for VariableMapping {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer
+                .deserialize_map(MappingVisitor)
+                .map(|m| VariableMapping(serde_yaml::Value::Mapping(m)))
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Export of the [`Type`]/[`Variable`] model to standard JSON Schema
+//! (draft-07), so external tooling can validate EVerest payloads with
+//! off-the-shelf validators instead of reimplementing EVerest's subset.
+
+use serde_json::{json, Map, Value};
+
+use super::interface::{
+    Argument, ArrayOptions, IntegerOptions, Interface, NumberOptions, ObjectOptions, StringOptions,
+    Type, Variable,
+};
+
+const DRAFT_07: &str = "http://json-schema.org/draft-07/schema#";
+
+impl Type {
+    /// Render this type as a draft-07 JSON Schema fragment.
+    pub fn to_json_schema(&self) -> Value {
+        match self {
+            Type::Null => json!({ "type": "null" }),
+            Type::Boolean => json!({ "type": "boolean" }),
+            Type::Number(opts) => number_schema(opts),
+            Type::Integer(opts) => integer_schema(opts),
+            Type::String(opts) => string_schema(opts),
+            Type::Array(opts) => array_schema(opts),
+            Type::Object(opts) => object_schema(opts),
+        }
+    }
+}
+
+impl Variable {
+    /// Render this variable as a draft-07 JSON Schema fragment, carrying its
+    /// `description` onto the schema.
+    pub fn to_json_schema(&self) -> Value {
+        let mut schema = argument_schema(&self.arg);
+        if let (Some(map), Some(description)) = (schema.as_object_mut(), &self.description) {
+            map.insert("description".to_string(), json!(description));
+        }
+        schema
+    }
+}
+
+impl Interface {
+    /// Produce one schema per command argument set, per command result, and
+    /// per var, keyed by a stable name. The top-level object declares the
+    /// draft-07 `$schema` and collects the fragments under `definitions`.
+    pub fn to_json_schema(&self) -> Value {
+        let mut definitions = Map::new();
+        for (name, command) in &self.cmds {
+            let mut properties = Map::new();
+            let mut required = Vec::new();
+            for (arg_name, variable) in &command.arguments {
+                properties.insert(arg_name.clone(), variable.to_json_schema());
+                required.push(Value::String(arg_name.clone()));
+            }
+            let mut args = Map::new();
+            args.insert("type".to_string(), json!("object"));
+            args.insert("properties".to_string(), Value::Object(properties));
+            if !required.is_empty() {
+                args.insert("required".to_string(), Value::Array(required));
+            }
+            definitions.insert(format!("cmd.{name}.arguments"), Value::Object(args));
+            if let Some(result) = &command.result {
+                definitions.insert(format!("cmd.{name}.result"), result.to_json_schema());
+            }
+        }
+        for (name, variable) in &self.vars {
+            definitions.insert(format!("var.{name}"), variable.to_json_schema());
+        }
+        json!({
+            "$schema": DRAFT_07,
+            "description": self.description,
+            "definitions": Value::Object(definitions),
+        })
+    }
+}
+
+fn argument_schema(arg: &Argument) -> Value {
+    match arg {
+        Argument::Single(t) => t.to_json_schema(),
+        Argument::Multiple(types) => {
+            json!({ "anyOf": types.iter().map(Type::to_json_schema).collect::<Vec<_>>() })
+        }
+    }
+}
+
+fn number_schema(opts: &NumberOptions) -> Value {
+    let mut map = Map::new();
+    map.insert("type".to_string(), json!("number"));
+    if let Some(min) = opts.minimum {
+        map.insert("minimum".to_string(), json!(min));
+    }
+    if let Some(max) = opts.maximum {
+        map.insert("maximum".to_string(), json!(max));
+    }
+    if let Some(default) = opts.default {
+        map.insert("default".to_string(), json!(default));
+    }
+    Value::Object(map)
+}
+
+fn integer_schema(opts: &IntegerOptions) -> Value {
+    let mut map = Map::new();
+    map.insert("type".to_string(), json!("integer"));
+    if let Some(min) = opts.minimum {
+        map.insert("minimum".to_string(), json!(min));
+    }
+    if let Some(max) = opts.maximum {
+        map.insert("maximum".to_string(), json!(max));
+    }
+    if let Some(default) = opts.default {
+        map.insert("default".to_string(), json!(default));
+    }
+    Value::Object(map)
+}
+
+fn string_schema(opts: &StringOptions) -> Value {
+    if let Some(reference) = &opts.object_reference {
+        return json!({ "$ref": reference });
+    }
+    let mut map = Map::new();
+    map.insert("type".to_string(), json!("string"));
+    if let Some(pattern) = &opts.pattern {
+        map.insert("pattern".to_string(), json!(pattern));
+    }
+    if let Some(min) = opts.min_length {
+        map.insert("minLength".to_string(), json!(min));
+    }
+    if let Some(max) = opts.max_length {
+        map.insert("maxLength".to_string(), json!(max));
+    }
+    if let Some(items) = &opts.enum_items {
+        map.insert("enum".to_string(), json!(items));
+    }
+    if let Some(format) = &opts.format {
+        map.insert("format".to_string(), json!(format.name()));
+    }
+    if let Some(default) = &opts.default {
+        map.insert("default".to_string(), json!(default));
+    }
+    Value::Object(map)
+}
+
+fn array_schema(opts: &ArrayOptions) -> Value {
+    let mut map = Map::new();
+    map.insert("type".to_string(), json!("array"));
+    if let Some(items) = &opts.items {
+        map.insert("items".to_string(), items.to_json_schema());
+    }
+    if let Some(min) = opts.min_items {
+        map.insert("minItems".to_string(), json!(min));
+    }
+    if let Some(max) = opts.max_items {
+        map.insert("maxItems".to_string(), json!(max));
+    }
+    Value::Object(map)
+}
+
+fn object_schema(opts: &ObjectOptions) -> Value {
+    if let Some(reference) = &opts.object_reference {
+        return json!({ "$ref": reference });
+    }
+    let mut properties = Map::new();
+    for (name, variable) in &opts.properties {
+        properties.insert(name.clone(), variable.to_json_schema());
+    }
+    let mut required: Vec<Value> = opts.required.iter().cloned().map(Value::String).collect();
+    required.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+    let mut map = Map::new();
+    map.insert("type".to_string(), json!("object"));
+    map.insert("properties".to_string(), Value::Object(properties));
+    if !required.is_empty() {
+        map.insert("required".to_string(), Value::Array(required));
+    }
+    map.insert(
+        "additionalProperties".to_string(),
+        json!(opts.additional_properties),
+    );
+    Value::Object(map)
+}
@@ -0,0 +1,235 @@
+//! Resolution of EVerest type `$ref`s into concrete [`Variable`] definitions.
+//!
+//! References look like `/path/to/type_file#/TypeName`: a *file part* pointing
+//! at a type-definition YAML document (relative to the registry's base
+//! directory) and a *fragment part* naming a definition inside that document's
+//! `types` map. The [`TypeRegistry`] loads and memoizes those documents and
+//! replaces every `$ref` with its target, detecting reference cycles along the
+//! way.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::interface::{Argument, Interface, ObjectOptions, Type, Variable};
+
+/// A type-definition document: a `types` map of named [`Variable`] definitions.
+#[derive(Debug, Deserialize)]
+struct TypeDocument {
+    #[serde(default)]
+    types: BTreeMap<String, Variable>,
+}
+
+/// Errors produced while resolving references.
+#[derive(Debug)]
+pub enum ResolveError {
+    /// A `$ref` string that is not of the form `file#/fragment`.
+    MalformedReference(String),
+    /// The referenced document could not be read or parsed.
+    Load { path: PathBuf, source: String },
+    /// The fragment does not name a definition in the referenced document.
+    UnknownFragment { path: PathBuf, fragment: String },
+    /// A `$ref` cycle was detected; the vector lists the cycle in order.
+    Cycle(Vec<String>),
+}
+
+impl std::fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolveError::MalformedReference(r) => {
+                write!(f, "malformed reference {r:?}, expected 'file#/fragment'")
+            }
+            ResolveError::Load { path, source } => {
+                write!(f, "failed to load type file {}: {source}", path.display())
+            }
+            ResolveError::UnknownFragment { path, fragment } => {
+                write!(f, "no definition {fragment:?} in {}", path.display())
+            }
+            ResolveError::Cycle(cycle) => {
+                write!(f, "reference cycle detected: {}", cycle.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+type Result<T> = std::result::Result<T, ResolveError>;
+
+/// Loads and memoizes type-definition documents and resolves `$ref`s against
+/// them.
+#[derive(Debug)]
+pub struct TypeRegistry {
+    base_dir: PathBuf,
+    documents: BTreeMap<PathBuf, TypeDocument>,
+}
+
+impl TypeRegistry {
+    /// Create a registry whose relative file parts are resolved against
+    /// `base_dir`.
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            documents: BTreeMap::new(),
+        }
+    }
+
+    /// Resolve the `$ref` carried by `options` to its target [`Variable`],
+    /// loading the referenced document if necessary. This follows a single
+    /// hop; use [`TypeRegistry::resolve_all`] to expand references deeply.
+    pub fn resolve(&mut self, options: &ObjectOptions) -> Result<&Variable> {
+        let reference = options.object_reference.as_deref().ok_or_else(|| {
+            ResolveError::MalformedReference("<object without a $ref>".to_string())
+        })?;
+        self.resolve_reference(reference)
+    }
+
+    /// Resolve a raw reference string to its target [`Variable`], following a
+    /// single hop.
+    fn resolve_reference(&mut self, reference: &str) -> Result<&Variable> {
+        let (path, fragment) = self.parse_reference(reference)?;
+        self.load(&path)?;
+        let doc = self.documents.get(&path).expect("just loaded");
+        doc.types
+            .get(&fragment)
+            .ok_or(ResolveError::UnknownFragment { path, fragment })
+    }
+
+    /// Eagerly walk `interface`, replacing every `$ref` in `cmds`, `vars`,
+    /// nested `properties`, and array `items` with its fully-expanded target so
+    /// downstream consumers never see an unresolved reference.
+    pub fn resolve_all(&mut self, interface: &mut Interface) -> Result<()> {
+        for command in interface.cmds.values_mut() {
+            for variable in command.arguments.values_mut() {
+                self.expand_variable(variable, &mut Vec::new())?;
+            }
+            if let Some(result) = command.result.as_mut() {
+                self.expand_variable(result, &mut Vec::new())?;
+            }
+        }
+        for variable in interface.vars.values_mut() {
+            self.expand_variable(variable, &mut Vec::new())?;
+        }
+        Ok(())
+    }
+
+    /// Recursively expand any references reachable from `variable` in place.
+    /// `in_progress` is the ordered stack of references currently being
+    /// resolved, used to report cycles deterministically.
+    fn expand_variable(
+        &mut self,
+        variable: &mut Variable,
+        in_progress: &mut Vec<String>,
+    ) -> Result<()> {
+        match &mut variable.arg {
+            Argument::Single(t) => self.expand_type(t, in_progress)?,
+            Argument::Multiple(types) => {
+                for t in types.iter_mut() {
+                    self.expand_type(t, in_progress)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn expand_type(&mut self, t: &mut Type, in_progress: &mut Vec<String>) -> Result<()> {
+        match t {
+            Type::Array(opts) => {
+                if let Some(items) = opts.items.as_mut() {
+                    self.expand_variable(items, in_progress)?;
+                }
+            }
+            Type::String(opts) => {
+                if let Some(reference) = opts.object_reference.clone() {
+                    let target = self.resolve_deep(&reference, in_progress)?;
+                    // Replace the whole type with the referenced definition; a
+                    // union target is not representable as a single `Type`, so
+                    // drop the now-resolved ref and keep the string as-is.
+                    if let Argument::Single(inner) = target.arg {
+                        *t = inner;
+                        return self.expand_type(t, in_progress);
+                    }
+                    opts.object_reference = None;
+                }
+            }
+            Type::Object(opts) => {
+                if let Some(reference) = opts.object_reference.clone() {
+                    let target = self.resolve_deep(&reference, in_progress)?;
+                    // Replace the whole type with the referenced definition.
+                    *t = match target.arg {
+                        Argument::Single(inner) => inner,
+                        // A union target is not representable as a single
+                        // `Type`; keep the object but drop the now-resolved ref.
+                        Argument::Multiple(_) => {
+                            opts.object_reference = None;
+                            return Ok(());
+                        }
+                    };
+                    return self.expand_type(t, in_progress);
+                }
+                for variable in opts.properties.values_mut() {
+                    self.expand_variable(variable, in_progress)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Resolve a reference to an owned, fully-expanded [`Variable`], pushing it
+    /// onto `in_progress` so a reference that re-enters itself is reported as a
+    /// cycle in the order it was encountered.
+    fn resolve_deep(
+        &mut self,
+        reference: &str,
+        in_progress: &mut Vec<String>,
+    ) -> Result<Variable> {
+        let (path, fragment) = self.parse_reference(reference)?;
+        let key = format!("{}#/{}", path.display(), fragment);
+        if in_progress.contains(&key) {
+            let mut cycle = in_progress.clone();
+            cycle.push(key);
+            return Err(ResolveError::Cycle(cycle));
+        }
+        in_progress.push(key);
+        let mut target = self.resolve_reference(reference)?.clone();
+        self.expand_variable(&mut target, in_progress)?;
+        in_progress.pop();
+        Ok(target)
+    }
+
+    /// Load and memoize the document at `path`.
+    fn load(&mut self, path: &Path) -> Result<()> {
+        if self.documents.contains_key(path) {
+            return Ok(());
+        }
+        let contents = std::fs::read_to_string(path).map_err(|e| ResolveError::Load {
+            path: path.to_path_buf(),
+            source: e.to_string(),
+        })?;
+        let doc: TypeDocument =
+            serde_yaml::from_str(&contents).map_err(|e| ResolveError::Load {
+                path: path.to_path_buf(),
+                source: e.to_string(),
+            })?;
+        self.documents.insert(path.to_path_buf(), doc);
+        Ok(())
+    }
+
+    /// Split a reference into its resolved file path and fragment name.
+    fn parse_reference(&self, reference: &str) -> Result<(PathBuf, String)> {
+        let (file, fragment) = reference
+            .split_once('#')
+            .ok_or_else(|| ResolveError::MalformedReference(reference.to_string()))?;
+        let fragment = fragment.trim_start_matches('/');
+        if file.is_empty() || fragment.is_empty() {
+            return Err(ResolveError::MalformedReference(reference.to_string()));
+        }
+        let mut path = self.base_dir.join(file.trim_start_matches('/'));
+        if path.extension().is_none() {
+            path.set_extension("yaml");
+        }
+        Ok((path, fragment.to_string()))
+    }
+}
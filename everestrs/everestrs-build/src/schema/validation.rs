@@ -0,0 +1,382 @@
+//! Runtime validation of concrete payloads against the [`Interface`] schema.
+//!
+//! The [`Type`] model carries a rich set of constraints (`minimum`/`maximum`,
+//! `minItems`/`maxItems`, `minLength`/`maxLength`, `pattern`, `enum`,
+//! `required`, `additionalProperties`, ...) that the deserializer parses but
+//! never enforces. This module walks a [`Type`] recursively against a
+//! `serde_yaml::Value` and reports *every* constraint violation rather than
+//! bailing on the first.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use regex::Regex;
+
+use super::interface::{
+    Argument, ArrayOptions, Command, IntegerOptions, NumberOptions, ObjectOptions, StringFormat,
+    StringOptions, Type, Variable,
+};
+
+/// A single constraint violation found while validating a payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON-pointer-style path to the offending location, e.g. `/foo/bar/2`.
+    /// The empty string refers to the root value.
+    pub path: String,
+    /// Human-readable description of the violated constraint.
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.path.is_empty() { "/" } else { &self.path };
+        write!(f, "{path}: {}", self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Compiled regexes keyed by their source pattern, so the same `pattern`
+/// string is only compiled once across a process.
+fn pattern_cache() -> &'static Mutex<HashMap<String, Regex>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Compile `pattern`, returning a cached copy on subsequent calls. Invalid
+/// patterns are not cached and surface as `Err`.
+fn compiled_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    if let Some(re) = pattern_cache().lock().unwrap().get(pattern) {
+        return Ok(re.clone());
+    }
+    let re = Regex::new(pattern)?;
+    pattern_cache()
+        .lock()
+        .unwrap()
+        .insert(pattern.to_string(), re.clone());
+    Ok(re)
+}
+
+/// Join a parent pointer with a child token, escaping per RFC 6901.
+fn join(path: &str, token: &str) -> String {
+    let escaped = token.replace('~', "~0").replace('/', "~1");
+    format!("{path}/{escaped}")
+}
+
+impl Type {
+    /// Validate `value` against this type, accumulating *all* failures.
+    pub fn validate(&self, value: &serde_yaml::Value) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        self.validate_at("", value, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn validate_at(&self, path: &str, value: &serde_yaml::Value, errors: &mut Vec<ValidationError>) {
+        match self {
+            Type::Null => {
+                if !value.is_null() {
+                    push(errors, path, "expected null");
+                }
+            }
+            Type::Boolean => {
+                if value.as_bool().is_none() {
+                    push(errors, path, "expected a boolean");
+                }
+            }
+            Type::Number(opts) => validate_number(opts, path, value, errors),
+            Type::Integer(opts) => validate_integer(opts, path, value, errors),
+            Type::String(opts) => validate_string(opts, path, value, errors),
+            Type::Array(opts) => validate_array(opts, path, value, errors),
+            Type::Object(opts) => validate_object(opts, path, value, errors),
+        }
+    }
+}
+
+fn validate_number(
+    opts: &NumberOptions,
+    path: &str,
+    value: &serde_yaml::Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(n) = value.as_f64() else {
+        push(errors, path, "expected a number");
+        return;
+    };
+    if let Some(min) = opts.minimum {
+        if n < min {
+            push(errors, path, &format!("{n} is less than minimum {min}"));
+        }
+    }
+    if let Some(max) = opts.maximum {
+        if n > max {
+            push(errors, path, &format!("{n} is greater than maximum {max}"));
+        }
+    }
+}
+
+fn validate_integer(
+    opts: &IntegerOptions,
+    path: &str,
+    value: &serde_yaml::Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(n) = value.as_i64() else {
+        push(errors, path, "expected an integer");
+        return;
+    };
+    if let Some(min) = opts.minimum {
+        if n < min {
+            push(errors, path, &format!("{n} is less than minimum {min}"));
+        }
+    }
+    if let Some(max) = opts.maximum {
+        if n > max {
+            push(errors, path, &format!("{n} is greater than maximum {max}"));
+        }
+    }
+}
+
+fn validate_string(
+    opts: &StringOptions,
+    path: &str,
+    value: &serde_yaml::Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(s) = value.as_str() else {
+        push(errors, path, "expected a string");
+        return;
+    };
+    let len = s.chars().count();
+    if let Some(min) = opts.min_length {
+        if len < min {
+            push(errors, path, &format!("length {len} is below minLength {min}"));
+        }
+    }
+    if let Some(max) = opts.max_length {
+        if len > max {
+            push(errors, path, &format!("length {len} exceeds maxLength {max}"));
+        }
+    }
+    if let Some(pattern) = &opts.pattern {
+        match compiled_pattern(pattern) {
+            Ok(re) => {
+                if !re.is_match(s) {
+                    push(errors, path, &format!("does not match pattern {pattern:?}"));
+                }
+            }
+            Err(e) => push(errors, path, &format!("invalid pattern {pattern:?}: {e}")),
+        }
+    }
+    if let Some(items) = &opts.enum_items {
+        if !items.iter().any(|item| item == s) {
+            push(errors, path, &format!("{s:?} is not one of the permitted values"));
+        }
+    }
+    if let Some(format) = &opts.format {
+        if !format.check(s) {
+            push(errors, path, &format!("{s:?} is not a valid {}", format.name()));
+        }
+    }
+}
+
+impl StringFormat {
+    /// Check whether `value` conforms to this format. Formats the crate does
+    /// not understand ([`StringFormat::Other`]) impose no constraint and always
+    /// pass, so unknown `format` keywords never reject otherwise-valid strings.
+    pub fn check(&self, value: &str) -> bool {
+        match self {
+            StringFormat::DateTime => chrono::DateTime::parse_from_rfc3339(value).is_ok(),
+            StringFormat::Date => {
+                chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+            }
+            StringFormat::Time => {
+                // RFC 3339 `full-time` is `partial-time` plus a mandatory
+                // `time-offset` (`Z` or `±HH:MM`). Anchor the time to an
+                // arbitrary date and reuse the RFC 3339 parser so the offset is
+                // required and fractional seconds are accepted.
+                chrono::DateTime::parse_from_rfc3339(&format!("1970-01-01T{value}")).is_ok()
+            }
+            // The pattern alone also admits `P` and a trailing empty `T`
+            // section; reject those so at least one component is present.
+            StringFormat::Duration => {
+                matches_cached(DURATION_PATTERN, value) && value.len() > 1 && !value.ends_with('T')
+            }
+            StringFormat::Uuid => matches_cached(UUID_PATTERN, value),
+            StringFormat::Email => matches_cached(EMAIL_PATTERN, value),
+            StringFormat::Uri => matches_cached(URI_PATTERN, value),
+            StringFormat::Ipv4 => value.parse::<std::net::Ipv4Addr>().is_ok(),
+            StringFormat::Ipv6 => value.parse::<std::net::Ipv6Addr>().is_ok(),
+            StringFormat::Hostname => matches_cached(HOSTNAME_PATTERN, value),
+            StringFormat::Other(_) => true,
+        }
+    }
+}
+
+// ISO 8601 duration, e.g. `P3Y6M4DT12H30M5S` (at least one component required).
+const DURATION_PATTERN: &str =
+    r"^P(?:\d+Y)?(?:\d+M)?(?:\d+W)?(?:\d+D)?(?:T(?:\d+H)?(?:\d+M)?(?:\d+(?:\.\d+)?S)?)?$";
+// Canonical 8-4-4-4-12 hex UUID layout.
+const UUID_PATTERN: &str =
+    r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$";
+// Pragmatic addr-spec: one `@`, no whitespace, a dotted domain.
+const EMAIL_PATTERN: &str = r"^[^@\s]+@[^@\s]+\.[^@\s]+$";
+// `scheme:` followed by any non-whitespace characters; `//` is not required.
+const URI_PATTERN: &str = r"^[a-zA-Z][a-zA-Z0-9+.-]*:\S*$";
+// RFC 1123 hostname: dot-separated labels of letters, digits and hyphens.
+const HOSTNAME_PATTERN: &str =
+    r"^(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$";
+
+/// Compile (and cache) `pattern`, returning whether it matches `value`. An
+/// invalid built-in pattern fails closed.
+fn matches_cached(pattern: &str, value: &str) -> bool {
+    compiled_pattern(pattern)
+        .map(|re| re.is_match(value))
+        .unwrap_or(false)
+}
+
+fn validate_array(
+    opts: &ArrayOptions,
+    path: &str,
+    value: &serde_yaml::Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(seq) = value.as_sequence() else {
+        push(errors, path, "expected an array");
+        return;
+    };
+    if let Some(min) = opts.min_items {
+        if seq.len() < min {
+            push(errors, path, &format!("{} items is below minItems {min}", seq.len()));
+        }
+    }
+    if let Some(max) = opts.max_items {
+        if seq.len() > max {
+            push(errors, path, &format!("{} items exceeds maxItems {max}", seq.len()));
+        }
+    }
+    if let Some(items) = &opts.items {
+        for (i, element) in seq.iter().enumerate() {
+            validate_argument(&items.arg, &join(path, &i.to_string()), element, errors);
+        }
+    }
+}
+
+fn validate_object(
+    opts: &ObjectOptions,
+    path: &str,
+    value: &serde_yaml::Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(map) = value.as_mapping() else {
+        push(errors, path, "expected an object");
+        return;
+    };
+    // `required` is a `HashSet`; sort so the accumulated errors are stable.
+    let mut required: Vec<&String> = opts.required.iter().collect();
+    required.sort();
+    for key in required {
+        if !map.contains_key(serde_yaml::Value::String(key.clone())) {
+            push(errors, path, &format!("missing required property {key:?}"));
+        }
+    }
+    for (k, v) in map {
+        let Some(key) = k.as_str() else {
+            push(errors, path, "object keys must be strings");
+            continue;
+        };
+        match opts.properties.get(key) {
+            Some(variable) => validate_argument(&variable.arg, &join(path, key), v, errors),
+            None if !opts.additional_properties => {
+                push(errors, &join(path, key), "additional property is not permitted");
+            }
+            None => {}
+        }
+    }
+}
+
+/// Validate against an [`Argument`]: a single type, or — for
+/// [`Argument::Multiple`] — a union where the value must match any one member.
+fn validate_argument(
+    arg: &Argument,
+    path: &str,
+    value: &serde_yaml::Value,
+    errors: &mut Vec<ValidationError>,
+) {
+    match arg {
+        Argument::Single(t) => t.validate_at(path, value, errors),
+        Argument::Multiple(types) => {
+            let matches = types.iter().any(|t| {
+                let mut scratch = Vec::new();
+                t.validate_at(path, value, &mut scratch);
+                scratch.is_empty()
+            });
+            if !matches {
+                push(errors, path, "value does not match any of the permitted types");
+            }
+        }
+    }
+}
+
+fn push(errors: &mut Vec<ValidationError>, path: &str, message: &str) {
+    errors.push(ValidationError {
+        path: path.to_string(),
+        message: message.to_string(),
+    });
+}
+
+impl Variable {
+    /// Validate `value` against this variable's type.
+    pub fn validate(&self, value: &serde_yaml::Value) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        validate_argument(&self.arg, "", value, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl Command {
+    /// Validate a map of concrete call arguments against this command's
+    /// declared `arguments`. Each present argument is validated against its
+    /// declared type; arguments that are not declared are reported.
+    pub fn validate_arguments(
+        &self,
+        args: &serde_yaml::Value,
+    ) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let Some(map) = args.as_mapping() else {
+            push(&mut errors, "", "expected an argument map");
+            return Err(errors);
+        };
+        for (k, v) in map {
+            let Some(key) = k.as_str() else {
+                push(&mut errors, "", "argument names must be strings");
+                continue;
+            };
+            match self.arguments.get(key) {
+                Some(variable) => validate_argument(&variable.arg, &join("", key), v, &mut errors),
+                None => push(&mut errors, &join("", key), "unknown argument"),
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate a concrete result value against this command's `result` type.
+    /// Commands without a declared result accept any value.
+    pub fn validate_result(&self, value: &serde_yaml::Value) -> Result<(), Vec<ValidationError>> {
+        match &self.result {
+            Some(result) => result.validate(value),
+            None => Ok(()),
+        }
+    }
+}